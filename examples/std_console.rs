@@ -5,7 +5,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use toroid::Donut;
+use toroid::{color, Donut};
 
 fn main() {
     const WIDTH: u8 = 80;
@@ -14,9 +14,12 @@ fn main() {
 
     // Create the donut instance and pre allocate buffers
     let mut donut = Donut::<WIDTH, HEIGHT>::new();
-    let mut output = [' '; SIZE];
+    let mut rgb = [[0u8; 3]; SIZE];
     let mut zbuf = [0.0_f32; SIZE];
 
+    // Longest possible `write_ansi_fg` encoding, reused for every cell.
+    let mut ansi = [0u8; 19];
+
     // Clear the terminal
     print!("\x1B[2J");
     let stdout = stdout();
@@ -26,19 +29,24 @@ fn main() {
         // Start timing this frame
         let start = Instant::now();
 
-        // Render the donut into output and depth buffers
-        donut.render_frame_in_place(&mut output, &mut zbuf);
+        // Render the donut into the color and depth buffers
+        donut.render_frame_colored_in_place(&mut rgb, &mut zbuf);
 
         // Reset cursor to top left
         write!(handle, "\x1B[H").unwrap();
 
-        // Write output buffer
-        for line in output.chunks(WIDTH as usize) {
-            for &ch in line {
-                handle.write_all(&[ch as u8]).unwrap();
+        // Write the color buffer, one truecolor escape per lit cell
+        for line in rgb.chunks(WIDTH as usize) {
+            for &color in line {
+                if color != [0, 0, 0] {
+                    let n = color::write_ansi_fg(color, &mut ansi);
+                    handle.write_all(&ansi[..n]).unwrap();
+                }
+
+                handle.write_all(if color == [0, 0, 0] { b" " } else { b"#" }).unwrap();
             }
 
-            handle.write_all(b"\n").unwrap();
+            handle.write_all(b"\x1B[0m\n").unwrap();
         }
 
         // Write the status line with FPS and memory usage.
@@ -47,8 +55,7 @@ fn main() {
             "\nFPS: {:>5.1} | Approx Mem: {}",
             1.0 / start.elapsed().as_secs_f32().max(0.0001),
             {
-                let u =
-                    mem::size_of_val(&output) + mem::size_of_val(&zbuf) + mem::size_of_val(&donut);
+                let u = mem::size_of_val(&rgb) + mem::size_of_val(&zbuf) + mem::size_of_val(&donut);
 
                 if u < 1024 {
                     format!("{} bytes", u)
@@ -0,0 +1,128 @@
+//! Color conversions for driving terminal truecolor output and small
+//! embedded framebuffers from the donut's per-cell shading data.
+//!
+//! Everything here is `no_std` and allocation-free, matching the rest of
+//! the crate: no trigonometric intrinsics (which would pull in `libm`),
+//! just bit packing and a cheap `atan2` approximation good enough for
+//! mapping an angle to a hue.
+
+/// Packs an 8-bit-per-channel RGB color into 16-bit RGB565 (5/6/5 bits).
+pub const fn to_rgb565(rgb: [u8; 3]) -> u16 {
+    let [r, g, b] = rgb;
+
+    let r5 = (r as u16 >> 3) & 0x1F;
+    let g6 = (g as u16 >> 2) & 0x3F;
+    let b5 = (b as u16 >> 3) & 0x1F;
+
+    (r5 << 11) | (g6 << 5) | b5
+}
+
+/// Packs an 8-bit-per-channel RGB color and an alpha channel into 32-bit
+/// RGBA8888 (byte order: `[r, g, b, a]` from most to least significant).
+pub const fn to_rgba8888(rgb: [u8; 3], alpha: u8) -> u32 {
+    let [r, g, b] = rgb;
+
+    u32::from_be_bytes([r, g, b, alpha])
+}
+
+/// Converts a hue/saturation/value triple (`h` in turns `[0, 1)`, `s` and
+/// `v` in `[0, 1]`) into 8-bit-per-channel RGB.
+pub fn hsv_to_rgb(h: f32, s: f32, v: f32) -> [u8; 3] {
+    // `f32::rem_euclid` is `std`-only; wrap into `[0, 1)` by hand instead.
+    let h = h - (h as i32 as f32);
+    let h = if h < 0.0 { h + 1.0 } else { h };
+    let h = h * 6.0;
+    let s = s.clamp(0.0, 1.0);
+    let v = v.clamp(0.0, 1.0);
+
+    let i = h as i32;
+    let f = h - i as f32;
+
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - s * f);
+    let t = v * (1.0 - s * (1.0 - f));
+
+    let (r, g, b) = match i.rem_euclid(6) {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    [(r * 255.0) as u8, (g * 255.0) as u8, (b * 255.0) as u8]
+}
+
+/// Approximates `atan2(y, x)` in radians, returning a value in `(-pi, pi]`.
+///
+/// Accurate to within ~0.07 radians, which is plenty for turning a
+/// `(cos, sin)` pair already tracked by the renderer into a hue angle
+/// without pulling in `libm`.
+pub(crate) fn atan2_approx(y: f32, x: f32) -> f32 {
+    use core::f32::consts::FRAC_PI_4;
+
+    const THREE_QUARTER_PI: f32 = 3.0 * FRAC_PI_4;
+
+    let abs_y = y.abs() + 1.0e-10;
+
+    let angle = if x >= 0.0 {
+        let r = (x - abs_y) / (x + abs_y);
+        FRAC_PI_4 - FRAC_PI_4 * r
+    } else {
+        let r = (x + abs_y) / (abs_y - x);
+        THREE_QUARTER_PI - FRAC_PI_4 * r
+    };
+
+    if y < 0.0 {
+        -angle
+    } else {
+        angle
+    }
+}
+
+/// Writes the ANSI truecolor escape sequence `\x1b[38;2;r;g;bm`, which sets
+/// the terminal foreground color to `rgb`, into `out`, returning the
+/// number of bytes written.
+///
+/// `out` must be at least 19 bytes long (the longest possible encoding,
+/// with every channel at 3 digits).
+pub fn write_ansi_fg(rgb: [u8; 3], out: &mut [u8]) -> usize {
+    let prefix = b"\x1b[38;2;";
+    out[..prefix.len()].copy_from_slice(prefix);
+    let mut pos = prefix.len();
+
+    for (i, &channel) in rgb.iter().enumerate() {
+        if i > 0 {
+            out[pos] = b';';
+            pos += 1;
+        }
+        pos += write_decimal(channel, &mut out[pos..]);
+    }
+
+    out[pos] = b'm';
+    pos + 1
+}
+
+/// Writes `value` as ASCII decimal digits into `out`, returning the number
+/// of digits written. `out` must be at least 3 bytes long.
+fn write_decimal(mut value: u8, out: &mut [u8]) -> usize {
+    let mut digits = [0u8; 3];
+    let mut len = 0;
+
+    loop {
+        digits[len] = b'0' + value % 10;
+        value /= 10;
+        len += 1;
+
+        if value == 0 {
+            break;
+        }
+    }
+
+    for i in 0..len {
+        out[i] = digits[len - 1 - i];
+    }
+
+    len
+}
@@ -0,0 +1,61 @@
+//! Small, `no_std`-friendly approximations for the handful of transcendental
+//! functions the crate needs, so nothing here has to depend on `libm`.
+//!
+//! These trade a little precision for speed and for staying
+//! allocation/dependency-free; that's an acceptable trade for shading and
+//! orientation math that only ever drives what a terminal renders.
+
+/// Fast inverse square root (the Quake III bit-hack, with one
+/// Newton-Raphson refinement).
+pub(crate) fn inv_sqrt(x: f32) -> f32 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    let i = 0x5f3759df - (x.to_bits() >> 1);
+    let y = f32::from_bits(i);
+
+    y * (1.5 - 0.5 * x * y * y)
+}
+
+/// Fast, approximate `base.powf(exponent)` for `base >= 0`, using the
+/// classic bit-level log2/exp2 approximation.
+pub(crate) fn powf_approx(base: f32, exponent: f32) -> f32 {
+    if base <= 0.0 {
+        return 0.0;
+    }
+
+    const BIAS: f32 = 1_065_353_216.0;
+    let scaled = (base.to_bits() as f32 - BIAS) * exponent + BIAS;
+
+    f32::from_bits(scaled as u32)
+}
+
+/// Approximates `(sin(x), cos(x))` for any `x` in radians using Bhaskara
+/// I's approximation (max error ~0.0016), which only needs `x` wrapped
+/// into `[0, pi]` plus a couple of sign flips.
+pub(crate) fn sin_cos_approx(x: f32) -> (f32, f32) {
+    (sin_approx(x), sin_approx(x + core::f32::consts::FRAC_PI_2))
+}
+
+fn sin_approx(x: f32) -> f32 {
+    const PI: f32 = core::f32::consts::PI;
+    const TAU: f32 = core::f32::consts::TAU;
+
+    // Wrap into (-pi, pi].
+    let mut x = x % TAU;
+    if x > PI {
+        x -= TAU;
+    } else if x < -PI {
+        x += TAU;
+    }
+
+    // Bhaskara I's approximation is stated for x in [0, pi]; mirror
+    // negative inputs through sin(-x) = -sin(x).
+    let (sign, x) = if x < 0.0 { (-1.0, -x) } else { (1.0, x) };
+
+    let numerator = 16.0 * x * (PI - x);
+    let denominator = 5.0 * PI * PI - 4.0 * x * (PI - x);
+
+    sign * numerator / denominator
+}
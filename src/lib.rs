@@ -1,24 +1,78 @@
-#![no_std]
+#![cfg_attr(not(feature = "parallel"), no_std)]
 
 use core::f32::consts::TAU;
 
+pub mod color;
+pub(crate) mod fastmath;
+pub mod lighting;
+// Enabled by the `parallel` feature declared under `[features]` in Cargo.toml.
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod quaternion;
+pub mod vector;
+
+use color::{atan2_approx, hsv_to_rgb};
+use lighting::{Light, Lighting};
+use quaternion::Quaternion;
+use vector::{FramePacing, Point};
+
+/// Rotates a local-space vector `v` into world space with 3x3 matrix `m`.
+fn apply_rotation(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Composes two 3x3 rotation matrices: `mat_mul(a, b)` applies `b`'s
+/// rotation first, then `a`'s (i.e. it computes `a * b`).
+fn mat_mul(a: &[[f32; 3]; 3], b: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+
+    for row in 0..3 {
+        for col in 0..3 {
+            out[row][col] = a[row][0] * b[0][col] + a[row][1] * b[1][col] + a[row][2] * b[2][col];
+        }
+    }
+
+    out
+}
+
+/// Builds the 3x3 matrix for a rotation of `angle` radians about the Y
+/// (screen-vertical) axis, used to compose [`Donut::render_eye`]'s toe-in
+/// on top of the donut's current orientation.
+fn rotation_about_y(angle: f32) -> [[f32; 3]; 3] {
+    let (s, c) = fastmath::sin_cos_approx(angle);
+
+    [[c, 0.0, s], [0.0, 1.0, 0.0], [-s, 0.0, c]]
+}
+
 /// A configurable "Donut" that can render ASCII frames without `std`.
 ///
-/// The donut is rendered by sampling points on a torus surface using two angles,
-/// and projecting those points into 2D screen space. The brightness of each
-/// point is determined by the surface's orientation relative to a light source.
+/// The donut is rendered by sampling points on a torus surface (with
+/// configurable `RING_RADIUS`/`TUBE_RADIUS`) using two angles, and
+/// projecting those points into 2D screen space. The brightness of each
+/// point is determined by its surface normal's orientation relative to the
+/// configurable `lighting` model (see [`lighting::Lighting`]).
 pub struct Donut<
     const WIDTH: u8 = 80,
     const HEIGHT: u8 = 22,
     //
     const VIEWER_DISTANCE: u8 = 5,
     const BRIGHTNESS_FACTOR: u8 = 8,
+    const MAX_LIGHTS: usize = 4,
     //
     const J_STEP_VALUE: u8 = 7,
     const J_STEP_DENOM: u8 = 100,
     const I_STEP_VALUE: u8 = 2,
     const I_STEP_DENOM: u8 = 100,
     //
+    const RING_RADIUS_VALUE: u8 = 2,
+    const RING_RADIUS_DENOM: u8 = 1,
+    const TUBE_RADIUS_VALUE: u8 = 1,
+    const TUBE_RADIUS_DENOM: u8 = 1,
+    //
     const CHAR_BRIGHTNESS_0: char = ' ',
     const CHAR_BRIGHTNESS_1: char = '.',
     const CHAR_BRIGHTNESS_2: char = ',',
@@ -33,13 +87,25 @@ pub struct Donut<
     const CHAR_BRIGHTNESS_11: char = '$',
     const CHAR_BRIGHTNESS_12: char = '@',
 > {
-    // Rotation angle A (vertical axis)
-    pub a_cos: f32,
-    pub a_sin: f32,
+    /// The donut's current orientation. [`Self::rotate_axis`] is the
+    /// source of truth; `rotation` below is cached from it.
+    pub orientation: Quaternion,
 
-    // Rotation angle B (horizontal axis)
-    pub b_cos: f32,
-    pub b_sin: f32,
+    /// The 3x3 rotation matrix `orientation` represents, cached so the
+    /// renderer can apply it to each torus point and normal directly
+    /// instead of re-deriving it from the quaternion every cell.
+    ///
+    /// A plain `(a_cos, a_sin, b_cos, b_sin)` cache (what this crate used
+    /// before) only reproduces a rigid rotation for `Rz(B) * Rx(A)`
+    /// compositions; for an orientation built from an arbitrary axis those
+    /// four scalars stop being unit cosine/sine pairs and the renderer
+    /// distorts the figure instead of turning it. The full matrix has no
+    /// such blind spot.
+    rotation: [[f32; 3]; 3],
+
+    /// The ambient/directional-light shading model used to compute each
+    /// rendered cell's brightness.
+    pub lighting: Lighting<MAX_LIGHTS>,
 }
 
 impl<
@@ -48,12 +114,18 @@ impl<
     //
     const VIEWER_DISTANCE: u8,
     const BRIGHTNESS_FACTOR: u8,
+    const MAX_LIGHTS: usize,
     //
     const J_STEP_VALUE: u8,
     const J_STEP_DENOM: u8,
     const I_STEP_VALUE: u8,
     const I_STEP_DENOM: u8,
     //
+    const RING_RADIUS_VALUE: u8,
+    const RING_RADIUS_DENOM: u8,
+    const TUBE_RADIUS_VALUE: u8,
+    const TUBE_RADIUS_DENOM: u8,
+    //
     const C0: char,
     const C1: char,
     const C2: char,
@@ -73,10 +145,15 @@ impl<
         HEIGHT,
         VIEWER_DISTANCE,
         BRIGHTNESS_FACTOR,
+        MAX_LIGHTS,
         J_STEP_VALUE,
         J_STEP_DENOM,
         I_STEP_VALUE,
         I_STEP_DENOM,
+        RING_RADIUS_VALUE,
+        RING_RADIUS_DENOM,
+        TUBE_RADIUS_VALUE,
+        TUBE_RADIUS_DENOM,
         C0,
         C1,
         C2,
@@ -114,50 +191,128 @@ impl<
         if x > n as f32 { n + 1 } else { n }
     };
 
+    const RING_RADIUS: f32 = match RING_RADIUS_DENOM {
+        0 | 1 => RING_RADIUS_VALUE as f32,
+        _ => (RING_RADIUS_VALUE as f32) / (RING_RADIUS_DENOM as f32),
+    };
+
+    const TUBE_RADIUS: f32 = match TUBE_RADIUS_DENOM {
+        0 | 1 => TUBE_RADIUS_VALUE as f32,
+        _ => (TUBE_RADIUS_VALUE as f32) / (TUBE_RADIUS_DENOM as f32),
+    };
+
+    /// The farthest a point on the surface ever gets from the torus's
+    /// center axis, used to keep the projection auto-scaled as
+    /// `RING_RADIUS`/`TUBE_RADIUS` change.
+    const MAX_RADIUS: f32 = Self::RING_RADIUS + Self::TUBE_RADIUS;
+
     const X_CENTER: f32 = WIDTH as f32 / 2.0;
     const Y_CENTER: f32 = HEIGHT as f32 / 2.0;
 
-    const X_SCALE: f32 = 30.0 * (WIDTH as f32 / 80.0);
-    const Y_SCALE: f32 = 15.0 * (HEIGHT as f32 / 22.0);
+    // 18 and 9 are chosen so the defaults (RING_RADIUS=2, TUBE_RADIUS=1,
+    // VIEWER_DISTANCE=5) reproduce the original hardcoded 30/15 scale.
+    const X_SCALE: f32 = 18.0 * (VIEWER_DISTANCE as f32 / Self::MAX_RADIUS) * (WIDTH as f32 / 80.0);
+    const Y_SCALE: f32 = 9.0 * (VIEWER_DISTANCE as f32 / Self::MAX_RADIUS) * (HEIGHT as f32 / 22.0);
 
     const BRIGHTNESS_RAMP: [char; 13] = [C0, C1, C2, C3, C4, C5, C6, C7, C8, C9, C10, C11, C12];
 
-    /// Create a new donut with initial rotation values set to represent 0 (cosine=1, sine=0).
+    /// Total number of cells in a full `WIDTH`×`HEIGHT` frame buffer.
+    const CELLS: usize = WIDTH as usize * HEIGHT as usize;
+
+    /// Total number of lattice points [`Self::frame_points`] walks.
+    const NUM_POINTS: usize = Self::NUM_J * Self::NUM_I;
+
+    /// The small inward rotation ("toe-in") applied to each eye in
+    /// [`Self::render_stereo_in_place`], converging the two views toward
+    /// the torus's center for comfortable viewing.
+    const TOE_IN_ANGLE: f32 = 0.05;
+
+    /// The view direction used for the specular term, pointing from the
+    /// torus surface back toward the (fixed, centered) viewer.
+    const VIEW_DIR: [f32; 3] = [0.0, 0.0, 1.0];
+
+    /// The axis `rotate`'s `da` turns around (matches the original
+    /// hardcoded "angle A" axis).
+    const X_AXIS: [f32; 3] = [1.0, 0.0, 0.0];
+
+    /// The axis `rotate`'s `db` turns around (matches the original
+    /// hardcoded "angle B" axis).
+    const Z_AXIS: [f32; 3] = [0.0, 0.0, 1.0];
+
+    /// How far `orientation`'s squared norm may drift from `1.0` before a
+    /// [`Self::rotate_axis`] update is rejected outright instead of just
+    /// being renormalized.
+    const QUATERNION_NORM_TOLERANCE: f32 = 0.1;
+
+    /// Computes the unit surface normal of the torus point at tube angle
+    /// `(j_cos, j_sin)` and ring angle `(i_cos, i_sin)`, after applying the
+    /// donut's current orientation.
+    ///
+    /// The pre-rotation normal `(i_cos*j_cos, i_sin*j_cos, j_sin)` points
+    /// radially out from the tube's own center axis; `rotation` (the
+    /// donut's current orientation, or a stand-in like a toed-in stereo
+    /// eye's) carries it into world space.
+    fn surface_normal(j_cos: f32, j_sin: f32, i_cos: f32, i_sin: f32, rotation: &[[f32; 3]; 3]) -> [f32; 3] {
+        apply_rotation(rotation, [i_cos * j_cos, i_sin * j_cos, j_sin])
+    }
+
+    /// Create a new donut with initial rotation values set to represent 0 (cosine=1, sine=0),
+    /// and a default single-light lighting model matching the direction this renderer used
+    /// to hardcode.
     pub const fn new() -> Self {
+        let mut lighting = Lighting::new(0.0);
+
+        lighting.push_light(Light {
+            direction: [0.0, -core::f32::consts::FRAC_1_SQRT_2, -core::f32::consts::FRAC_1_SQRT_2],
+            intensity: core::f32::consts::SQRT_2,
+        });
+
         Self {
-            a_cos: 1.0,
-            a_sin: 0.0,
-            b_cos: 1.0,
-            b_sin: 0.0,
+            orientation: Quaternion::IDENTITY,
+            rotation: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            lighting,
         }
     }
 
-    /// Increment the rotation angles by `da` and `db`.
+    /// Increment the rotation by `da` around the X axis and `db` around the
+    /// Z axis.
     ///
-    /// Rotating the donut creates the animation effect.
+    /// Rotating the donut creates the animation effect. This is now a thin
+    /// wrapper over [`Self::rotate_axis`], kept for backward compatibility;
+    /// `a`/`b` are no longer tracked as independent Euler angles
+    /// internally, a single quaternion is, so ordinary small per-frame
+    /// increments look identical to before, but this is no longer
+    /// bit-for-bit the old linear-approximation scheme for unusual
+    /// sequences of large increments.
     pub fn rotate(&mut self, da: f32, db: f32) {
-        {
-            let temp = self.a_cos;
-
-            self.a_cos -= da * self.a_sin;
-            self.a_sin += da * temp;
-
-            let norm = (3.0 - (self.a_cos * self.a_cos + self.a_sin * self.a_sin)) / 2.0;
+        self.rotate_axis(Self::X_AXIS, da);
+        self.rotate_axis(Self::Z_AXIS, db);
+    }
 
-            self.a_cos *= norm;
-            self.a_sin *= norm;
+    /// Rotates the donut by `angle` radians about an arbitrary `axis`
+    /// (normalized internally), by composing a delta quaternion onto
+    /// [`Self::orientation`].
+    ///
+    /// Guards against long-running animations ever blowing up the way
+    /// flight-control code does: if the composed quaternion has a
+    /// non-finite component, or its squared norm has drifted outside
+    /// [`Self::QUATERNION_NORM_TOLERANCE`] of `1.0`, the update is rejected
+    /// and the previous orientation is kept instead of being normalized
+    /// into something misleading.
+    pub fn rotate_axis(&mut self, axis: [f32; 3], angle: f32) {
+        let delta = Quaternion::from_axis_angle(axis, angle);
+        let candidate = delta.mul(self.orientation);
+
+        if !candidate.is_finite() {
+            return;
         }
-        {
-            let temp = self.b_cos;
-
-            self.b_cos -= db * self.b_sin;
-            self.b_sin += db * temp;
 
-            let norm = (3.0 - (self.b_cos * self.b_cos + self.b_sin * self.b_sin)) / 2.0;
-
-            self.b_cos *= norm;
-            self.b_sin *= norm;
+        if (candidate.norm_squared() - 1.0).abs() > Self::QUATERNION_NORM_TOLERANCE {
+            return;
         }
+
+        self.orientation = candidate.normalized();
+        self.rotation = self.orientation.to_rotation_matrix();
     }
 
     /// **Render** one ASCII frame **in-place**:
@@ -171,8 +326,7 @@ impl<
         output.fill(C0);
         zbuf.fill(0.0);
 
-        let (sa, ca) = (self.a_sin, self.a_cos);
-        let (sb, cb) = (self.b_sin, self.b_cos);
+        let rotation = &self.rotation;
 
         let mut j_cos = 1.0;
         let mut j_sin = 0.0;
@@ -182,12 +336,13 @@ impl<
             let mut i_sin = 0.0;
 
             for _ in 0..Self::NUM_I {
-                let h = j_cos + 2.0;
-                let t = i_sin * h * ca - j_sin * sa;
-                let d = 1.0 / (i_sin * h * sa + j_sin * ca + VIEWER_DISTANCE as f32);
+                let h = Self::TUBE_RADIUS * j_cos + Self::RING_RADIUS;
+                let z0 = Self::TUBE_RADIUS * j_sin;
+                let world = apply_rotation(rotation, [i_cos * h, i_sin * h, z0]);
+                let d = 1.0 / (world[2] + VIEWER_DISTANCE as f32);
 
-                let x = (Self::X_CENTER + Self::X_SCALE * d * (i_cos * h * cb - t * sb)) as isize;
-                let y = (Self::Y_CENTER + Self::Y_SCALE * d * (i_cos * h * sb + t * cb)) as isize;
+                let x = (Self::X_CENTER + Self::X_SCALE * d * world[0]) as isize;
+                let y = (Self::Y_CENTER + Self::Y_SCALE * d * world[1]) as isize;
 
                 if x >= 0 && x < WIDTH as isize && y >= 0 && y < HEIGHT as isize {
                     let idx = (y * (WIDTH as isize) + x) as usize;
@@ -195,11 +350,9 @@ impl<
                     if d > zbuf[idx] {
                         zbuf[idx] = d;
 
-                        let n = (BRIGHTNESS_FACTOR as f32
-                            * ((j_sin * sa - i_sin * j_cos * ca) * cb
-                                - i_sin * j_cos * sa
-                                - j_sin * ca
-                                - i_cos * j_cos * sb)) as isize;
+                        let normal = Self::surface_normal(j_cos, j_sin, i_cos, i_sin, rotation);
+                        let shade = self.lighting.shade(normal, Self::VIEW_DIR);
+                        let n = (BRIGHTNESS_FACTOR as f32 * shade) as isize;
 
                         output[idx] = Self::BRIGHTNESS_RAMP[n.clamp(0, 12) as usize];
                     }
@@ -229,6 +382,428 @@ impl<
             }
         }
     }
+
+    /// **Render** one truecolor frame **in-place**:
+    /// - `rgb`  should be (WIDTH*HEIGHT) in length, for storing per-cell colors.
+    /// - zbuf   should also be (WIDTH*HEIGHT) in length, for storing depth.
+    ///
+    /// This mirrors [`Self::render_frame_in_place`] pixel-for-pixel, except
+    /// instead of picking a brightness character it derives a color from
+    /// the surface's orientation: the torus-parameter angle `j` maps to
+    /// hue, and the same luminance term used for the ASCII ramp maps to
+    /// value, both fed through an HSV→RGB conversion. Pass the result
+    /// through [`color::to_rgb565`], [`color::to_rgba8888`], or
+    /// [`color::write_ansi_fg`] depending on the target display.
+    pub fn render_frame_colored_in_place(&self, rgb: &mut [[u8; 3]], zbuf: &mut [f32]) {
+        rgb.fill([0, 0, 0]);
+        zbuf.fill(0.0);
+
+        let rotation = &self.rotation;
+
+        let mut j_cos = 1.0;
+        let mut j_sin = 0.0;
+
+        for _ in 0..Self::NUM_J {
+            let hue = atan2_approx(j_sin, j_cos) / TAU;
+
+            let mut i_cos = 1.0;
+            let mut i_sin = 0.0;
+
+            for _ in 0..Self::NUM_I {
+                let h = Self::TUBE_RADIUS * j_cos + Self::RING_RADIUS;
+                let z0 = Self::TUBE_RADIUS * j_sin;
+                let world = apply_rotation(rotation, [i_cos * h, i_sin * h, z0]);
+                let d = 1.0 / (world[2] + VIEWER_DISTANCE as f32);
+
+                let x = (Self::X_CENTER + Self::X_SCALE * d * world[0]) as isize;
+                let y = (Self::Y_CENTER + Self::Y_SCALE * d * world[1]) as isize;
+
+                if x >= 0 && x < WIDTH as isize && y >= 0 && y < HEIGHT as isize {
+                    let idx = (y * (WIDTH as isize) + x) as usize;
+
+                    if d > zbuf[idx] {
+                        zbuf[idx] = d;
+
+                        let normal = Self::surface_normal(j_cos, j_sin, i_cos, i_sin, rotation);
+                        let shade = self.lighting.shade(normal, Self::VIEW_DIR);
+                        let n = (BRIGHTNESS_FACTOR as f32 * shade) as isize;
+                        let value = n.clamp(0, 12) as f32 / 12.0;
+
+                        rgb[idx] = hsv_to_rgb(hue, 1.0, value);
+                    }
+                }
+                {
+                    let temp = i_cos;
+
+                    i_cos -= Self::I_STEP * i_sin;
+                    i_sin += Self::I_STEP * temp;
+
+                    let norm = (3.0 - (i_cos * i_cos + i_sin * i_sin)) / 2.0;
+
+                    i_cos *= norm;
+                    i_sin *= norm;
+                }
+            }
+            {
+                let temp = j_cos;
+
+                j_cos -= Self::J_STEP * j_sin;
+                j_sin += Self::J_STEP * temp;
+
+                let norm = (3.0 - (j_cos * j_cos + j_sin * j_sin)) / 2.0;
+
+                j_cos *= norm;
+                j_sin *= norm;
+            }
+        }
+    }
+
+    /// **Render** one multi-pass (AOV) frame **in-place**, writing depth,
+    /// surface normal, and raw luminance buffers in a single traversal:
+    /// - `char_out` should be (WIDTH*HEIGHT) in length, for storing characters, or empty to skip.
+    /// - `depth`    should be (WIDTH*HEIGHT) in length; used as the z-buffer, so never skipped.
+    /// - `normal`   should be (WIDTH*HEIGHT) in length, for storing unit surface normals, or empty to skip.
+    /// - `value`    should be (WIDTH*HEIGHT) in length, for storing raw (pre-ramp) luminance, or empty to skip.
+    ///
+    /// This lets downstream users do their own post-processing — edge
+    /// detection from the normal pass, fog from depth, or re-coloring —
+    /// without re-running the (comparatively expensive) projection.
+    /// `char_out`, `normal`, and `value` can each be passed as an empty
+    /// slice to opt out of that pass and keep the `no_std` footprint
+    /// minimal; `depth` doubles as the z-buffer so it's always required.
+    pub fn render_passes_in_place(
+        &self,
+        char_out: &mut [char],
+        depth: &mut [f32],
+        normal: &mut [[f32; 3]],
+        value: &mut [f32],
+    ) {
+        let want_char = !char_out.is_empty();
+        let want_normal = !normal.is_empty();
+        let want_value = !value.is_empty();
+
+        if want_char {
+            char_out.fill(C0);
+        }
+        depth.fill(0.0);
+        if want_normal {
+            normal.fill([0.0, 0.0, 0.0]);
+        }
+        if want_value {
+            value.fill(0.0);
+        }
+
+        let rotation = &self.rotation;
+
+        let mut j_cos = 1.0;
+        let mut j_sin = 0.0;
+
+        for _ in 0..Self::NUM_J {
+            let mut i_cos = 1.0;
+            let mut i_sin = 0.0;
+
+            for _ in 0..Self::NUM_I {
+                let h = Self::TUBE_RADIUS * j_cos + Self::RING_RADIUS;
+                let z0 = Self::TUBE_RADIUS * j_sin;
+                let world = apply_rotation(rotation, [i_cos * h, i_sin * h, z0]);
+                let d = 1.0 / (world[2] + VIEWER_DISTANCE as f32);
+
+                let x = (Self::X_CENTER + Self::X_SCALE * d * world[0]) as isize;
+                let y = (Self::Y_CENTER + Self::Y_SCALE * d * world[1]) as isize;
+
+                if x >= 0 && x < WIDTH as isize && y >= 0 && y < HEIGHT as isize {
+                    let idx = (y * (WIDTH as isize) + x) as usize;
+
+                    if d > depth[idx] {
+                        depth[idx] = d;
+
+                        let n = Self::surface_normal(j_cos, j_sin, i_cos, i_sin, rotation);
+                        let raw_luminance = self.lighting.shade(n, Self::VIEW_DIR);
+
+                        if want_normal {
+                            normal[idx] = n;
+                        }
+                        if want_value {
+                            value[idx] = raw_luminance;
+                        }
+                        if want_char {
+                            let scaled = (BRIGHTNESS_FACTOR as f32 * raw_luminance) as isize;
+                            char_out[idx] = Self::BRIGHTNESS_RAMP[scaled.clamp(0, 12) as usize];
+                        }
+                    }
+                }
+                {
+                    let temp = i_cos;
+
+                    i_cos -= Self::I_STEP * i_sin;
+                    i_sin += Self::I_STEP * temp;
+
+                    let norm = (3.0 - (i_cos * i_cos + i_sin * i_sin)) / 2.0;
+
+                    i_cos *= norm;
+                    i_sin *= norm;
+                }
+            }
+            {
+                let temp = j_cos;
+
+                j_cos -= Self::J_STEP * j_sin;
+                j_sin += Self::J_STEP * temp;
+
+                let norm = (3.0 - (j_cos * j_cos + j_sin * j_sin)) / 2.0;
+
+                j_cos *= norm;
+                j_sin *= norm;
+            }
+        }
+    }
+
+    /// Renders one eye of a stereo pair: a copy of [`Self::render_frame_in_place`]
+    /// with `x_center_offset` added to `X_CENTER` (the eye-separation shift) and
+    /// an extra `toe_in` rotation about the Y axis composed on top of the
+    /// donut's current orientation (the inward convergence), also writing
+    /// out the raw (pre-ramp) luminance alongside each cell's character so
+    /// [`Self::render_stereo_in_place`] doesn't have to re-derive it for
+    /// the anaglyph buffer.
+    fn render_eye(
+        &self,
+        x_center_offset: f32,
+        toe_in: f32,
+        chars: &mut [char],
+        luminance: &mut [f32],
+        zbuf: &mut [f32],
+    ) {
+        chars.fill(C0);
+        luminance.fill(0.0);
+        zbuf.fill(0.0);
+
+        let rotation = &mat_mul(&rotation_about_y(toe_in), &self.rotation);
+
+        let x_center = Self::X_CENTER + x_center_offset;
+
+        let mut j_cos = 1.0;
+        let mut j_sin = 0.0;
+
+        for _ in 0..Self::NUM_J {
+            let mut i_cos = 1.0;
+            let mut i_sin = 0.0;
+
+            for _ in 0..Self::NUM_I {
+                let h = Self::TUBE_RADIUS * j_cos + Self::RING_RADIUS;
+                let z0 = Self::TUBE_RADIUS * j_sin;
+                let world = apply_rotation(rotation, [i_cos * h, i_sin * h, z0]);
+                let d = 1.0 / (world[2] + VIEWER_DISTANCE as f32);
+
+                let x = (x_center + Self::X_SCALE * d * world[0]) as isize;
+                let y = (Self::Y_CENTER + Self::Y_SCALE * d * world[1]) as isize;
+
+                if x >= 0 && x < WIDTH as isize && y >= 0 && y < HEIGHT as isize {
+                    let idx = (y * (WIDTH as isize) + x) as usize;
+
+                    if d > zbuf[idx] {
+                        zbuf[idx] = d;
+
+                        let n = Self::surface_normal(j_cos, j_sin, i_cos, i_sin, rotation);
+                        let raw_luminance = self.lighting.shade(n, Self::VIEW_DIR);
+                        let scaled = (BRIGHTNESS_FACTOR as f32 * raw_luminance) as isize;
+
+                        luminance[idx] = raw_luminance;
+                        chars[idx] = Self::BRIGHTNESS_RAMP[scaled.clamp(0, 12) as usize];
+                    }
+                }
+                {
+                    let temp = i_cos;
+
+                    i_cos -= Self::I_STEP * i_sin;
+                    i_sin += Self::I_STEP * temp;
+
+                    let norm = (3.0 - (i_cos * i_cos + i_sin * i_sin)) / 2.0;
+
+                    i_cos *= norm;
+                    i_sin *= norm;
+                }
+            }
+            {
+                let temp = j_cos;
+
+                j_cos -= Self::J_STEP * j_sin;
+                j_sin += Self::J_STEP * temp;
+
+                let norm = (3.0 - (j_cos * j_cos + j_sin * j_sin)) / 2.0;
+
+                j_cos *= norm;
+                j_sin *= norm;
+            }
+        }
+    }
+
+    /// **Render** one stereoscopic frame **in-place**, combining a left- and
+    /// right-eye render (separated by `interocular_distance`, toed in toward
+    /// the torus's center) into whichever of the two output formats the
+    /// caller wants:
+    /// - `side_by_side` should be `(2*WIDTH*HEIGHT)` in length (left eye then
+    ///   right eye, each `WIDTH` wide, per row), or empty to skip.
+    /// - `anaglyph` should be `(WIDTH*HEIGHT)` in length, red/cyan packed
+    ///   (left eye's luminance in the red channel, right eye's in green and
+    ///   blue), viewable through cheap red/cyan glasses, or empty to skip.
+    /// - `left_chars`/`right_chars`/`left_luminance`/`right_luminance`/
+    ///   `left_zbuf`/`right_zbuf` should each be `(WIDTH*HEIGHT)` in length;
+    ///   these are internal per-eye scratch space, so they're always
+    ///   required even if you only want one of the two output formats.
+    ///   (`Self::CELLS` can't size a stack array directly since it depends
+    ///   on the struct's generic parameters, so these come from the
+    ///   caller instead.)
+    ///
+    /// `interocular_distance` is in character columns (the same units as
+    /// `X_CENTER`); tune it to taste for comfortable viewing.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_stereo_in_place(
+        &self,
+        interocular_distance: f32,
+        side_by_side: &mut [char],
+        anaglyph: &mut [[u8; 3]],
+        left_chars: &mut [char],
+        right_chars: &mut [char],
+        left_luminance: &mut [f32],
+        right_luminance: &mut [f32],
+        left_zbuf: &mut [f32],
+        right_zbuf: &mut [f32],
+    ) {
+        self.render_eye(
+            -interocular_distance * 0.5,
+            -Self::TOE_IN_ANGLE,
+            left_chars,
+            left_luminance,
+            left_zbuf,
+        );
+        self.render_eye(
+            interocular_distance * 0.5,
+            Self::TOE_IN_ANGLE,
+            right_chars,
+            right_luminance,
+            right_zbuf,
+        );
+
+        if !side_by_side.is_empty() {
+            let width = WIDTH as usize;
+
+            for row in 0..HEIGHT as usize {
+                let src = row * width;
+                let dst = row * 2 * width;
+
+                side_by_side[dst..dst + width].copy_from_slice(&left_chars[src..src + width]);
+                side_by_side[dst + width..dst + 2 * width].copy_from_slice(&right_chars[src..src + width]);
+            }
+        }
+
+        if !anaglyph.is_empty() {
+            for idx in 0..Self::CELLS {
+                let red = (left_luminance[idx].clamp(0.0, 1.0) * 255.0) as u8;
+                let cyan = (right_luminance[idx].clamp(0.0, 1.0) * 255.0) as u8;
+
+                anaglyph[idx] = [red, cyan, cyan];
+            }
+        }
+    }
+
+    /// Emits the projected torus as an ordered stream of [`Point`]s, for
+    /// driving a galvanometer-style (ILDA-like) vector display instead of a
+    /// pixel grid: each ring (`j`) of the lattice is one connected scan-path
+    /// segment, with [`Point::blanked`] set on the first point of each ring
+    /// so the beam jumps there without drawing a stray connecting line.
+    ///
+    /// Unlike the raster renders, no z-buffering is performed — vector
+    /// displays draw every stroke, so hidden-surface removal doesn't apply.
+    /// `out` should be at least [`Self::NUM_POINTS`] long; only the first
+    /// `min(out.len(), Self::NUM_POINTS)` points are written. Returns the
+    /// number of points written.
+    pub fn frame_points(&self, out: &mut [Point]) -> usize {
+        let rotation = &self.rotation;
+
+        let mut j_cos = 1.0;
+        let mut j_sin = 0.0;
+
+        let mut written = 0;
+
+        'rings: for _ in 0..Self::NUM_J {
+            let mut i_cos = 1.0;
+            let mut i_sin = 0.0;
+
+            for i in 0..Self::NUM_I {
+                if written >= out.len() {
+                    break 'rings;
+                }
+
+                let h = Self::TUBE_RADIUS * j_cos + Self::RING_RADIUS;
+                let z0 = Self::TUBE_RADIUS * j_sin;
+                let world = apply_rotation(rotation, [i_cos * h, i_sin * h, z0]);
+                let d = 1.0 / (world[2] + VIEWER_DISTANCE as f32);
+
+                let x = Self::X_SCALE * d * world[0] / Self::X_CENTER;
+                let y = Self::Y_SCALE * d * world[1] / Self::Y_CENTER;
+
+                let normal = Self::surface_normal(j_cos, j_sin, i_cos, i_sin, rotation);
+                let brightness = self.lighting.shade(normal, Self::VIEW_DIR);
+
+                out[written] = Point {
+                    x: x.clamp(-1.0, 1.0),
+                    y: y.clamp(-1.0, 1.0),
+                    brightness: brightness.clamp(0.0, 1.0),
+                    blanked: i == 0,
+                };
+                written += 1;
+
+                {
+                    let temp = i_cos;
+
+                    i_cos -= Self::I_STEP * i_sin;
+                    i_sin += Self::I_STEP * temp;
+
+                    let norm = (3.0 - (i_cos * i_cos + i_sin * i_sin)) / 2.0;
+
+                    i_cos *= norm;
+                    i_sin *= norm;
+                }
+            }
+            {
+                let temp = j_cos;
+
+                j_cos -= Self::J_STEP * j_sin;
+                j_sin += Self::J_STEP * temp;
+
+                let norm = (3.0 - (j_cos * j_cos + j_sin * j_sin)) / 2.0;
+
+                j_cos *= norm;
+                j_sin *= norm;
+            }
+        }
+
+        written
+    }
+
+    /// Computes a frame-rate-stable pacing for [`Self::rotate`] and
+    /// [`Self::frame_points`]: the `da`/`db` that advance the rotation at
+    /// `angular_speed_a`/`angular_speed_b` radians/sec regardless of
+    /// `target_fps`, and how many of [`Self::NUM_POINTS`] fit in one frame
+    /// if the output device can only draw `points_per_second` points/sec
+    /// (e.g. a galvanometer's scan rate) — clamped to
+    /// [`Self::NUM_POINTS`] if the device can keep up with the whole
+    /// lattice every frame.
+    pub fn frame_pacing(
+        target_fps: f32,
+        angular_speed_a: f32,
+        angular_speed_b: f32,
+        points_per_second: f32,
+    ) -> FramePacing {
+        let frame_seconds = 1.0 / target_fps.max(1.0);
+        let point_budget = ((points_per_second * frame_seconds) as usize).min(Self::NUM_POINTS);
+
+        FramePacing {
+            da: angular_speed_a * frame_seconds,
+            db: angular_speed_b * frame_seconds,
+            point_budget,
+        }
+    }
 }
 
 impl Default for Donut {
@@ -0,0 +1,124 @@
+//! A small, `no_std`-friendly lighting model for shading the donut's
+//! surface: an ambient term plus a fixed-capacity list of directional
+//! lights, with an optional Phong specular term.
+//!
+//! `libm` isn't pulled in for this — normalization and specular falloff
+//! reuse the [`crate::fastmath`] approximations instead.
+
+use crate::fastmath::{inv_sqrt, powf_approx};
+
+/// A single directional light: a unit direction pointing *toward* the
+/// light source, and an intensity multiplier.
+#[derive(Clone, Copy, Debug)]
+pub struct Light {
+    pub direction: [f32; 3],
+    pub intensity: f32,
+}
+
+impl Light {
+    /// Creates a directional light from a (not necessarily unit) direction
+    /// vector, which is normalized, and an intensity.
+    pub fn new(direction: [f32; 3], intensity: f32) -> Self {
+        let [x, y, z] = direction;
+        let inv_len = inv_sqrt(x * x + y * y + z * z);
+
+        Self {
+            direction: [x * inv_len, y * inv_len, z * inv_len],
+            intensity,
+        }
+    }
+}
+
+/// An ambient + multi-light + optional-specular shading model, holding up
+/// to `MAX_LIGHTS` directional lights in a fixed-capacity array so the
+/// crate stays allocation-free.
+#[derive(Clone, Copy, Debug)]
+pub struct Lighting<const MAX_LIGHTS: usize = 4> {
+    pub ambient: f32,
+    pub specular_exponent: Option<f32>,
+    lights: [Light; MAX_LIGHTS],
+    light_count: usize,
+}
+
+impl<const MAX_LIGHTS: usize> Lighting<MAX_LIGHTS> {
+    const NO_LIGHT: Light = Light {
+        direction: [0.0, 0.0, 0.0],
+        intensity: 0.0,
+    };
+
+    /// Creates a lighting model with only an ambient term and no lights.
+    pub const fn new(ambient: f32) -> Self {
+        Self {
+            ambient,
+            specular_exponent: None,
+            lights: [Self::NO_LIGHT; MAX_LIGHTS],
+            light_count: 0,
+        }
+    }
+
+    /// Sets the Phong specular exponent, enabling a specular term.
+    pub const fn with_specular_exponent(mut self, exponent: f32) -> Self {
+        self.specular_exponent = Some(exponent);
+        self
+    }
+
+    /// Adds a directional light, returning `false` (and doing nothing) if
+    /// the fixed capacity `MAX_LIGHTS` has already been reached.
+    pub const fn push_light(&mut self, light: Light) -> bool {
+        if self.light_count >= MAX_LIGHTS {
+            return false;
+        }
+
+        self.lights[self.light_count] = light;
+        self.light_count += 1;
+
+        true
+    }
+
+    /// The lights currently held, in insertion order.
+    pub fn lights(&self) -> &[Light] {
+        &self.lights[..self.light_count]
+    }
+
+    /// Evaluates ambient + diffuse + (optional) specular shading for a
+    /// unit surface normal `n` and unit view direction `v`, both pointing
+    /// away from the surface.
+    pub fn shade(&self, n: [f32; 3], v: [f32; 3]) -> f32 {
+        let mut total = self.ambient;
+
+        for light in self.lights() {
+            total += light.intensity * dot(n, light.direction).max(0.0);
+
+            if let Some(exponent) = self.specular_exponent {
+                let r = reflect(light.direction, n);
+                total += light.intensity * powf_approx(dot(r, v).max(0.0), exponent);
+            }
+        }
+
+        total
+    }
+}
+
+impl<const MAX_LIGHTS: usize> Default for Lighting<MAX_LIGHTS> {
+    /// A single light matching the fixed direction the renderer used to
+    /// hardcode, so default rendering is unchanged.
+    fn default() -> Self {
+        use core::f32::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+        let mut lighting = Self::new(0.0);
+        lighting.push_light(Light {
+            direction: [0.0, -FRAC_1_SQRT_2, -FRAC_1_SQRT_2],
+            intensity: SQRT_2,
+        });
+        lighting
+    }
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn reflect(l: [f32; 3], n: [f32; 3]) -> [f32; 3] {
+    let d = 2.0 * dot(l, n);
+    [d * n[0] - l[0], d * n[1] - l[1], d * n[2] - l[2]]
+}
@@ -0,0 +1,214 @@
+//! An opt-in, `std`-only parallel rendering path, enabled with the
+//! `parallel` Cargo feature. Pulling in `std::thread` means the crate is
+//! no longer `no_std` when this feature is active (see the
+//! `cfg_attr` on the crate root), so it's kept out of the default build.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::{apply_rotation, Donut};
+
+impl<
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    //
+    const VIEWER_DISTANCE: u8,
+    const BRIGHTNESS_FACTOR: u8,
+    const MAX_LIGHTS: usize,
+    //
+    const J_STEP_VALUE: u8,
+    const J_STEP_DENOM: u8,
+    const I_STEP_VALUE: u8,
+    const I_STEP_DENOM: u8,
+    //
+    const RING_RADIUS_VALUE: u8,
+    const RING_RADIUS_DENOM: u8,
+    const TUBE_RADIUS_VALUE: u8,
+    const TUBE_RADIUS_DENOM: u8,
+    //
+    const C0: char,
+    const C1: char,
+    const C2: char,
+    const C3: char,
+    const C4: char,
+    const C5: char,
+    const C6: char,
+    const C7: char,
+    const C8: char,
+    const C9: char,
+    const C10: char,
+    const C11: char,
+    const C12: char,
+>
+    Donut<
+        WIDTH,
+        HEIGHT,
+        VIEWER_DISTANCE,
+        BRIGHTNESS_FACTOR,
+        MAX_LIGHTS,
+        J_STEP_VALUE,
+        J_STEP_DENOM,
+        I_STEP_VALUE,
+        I_STEP_DENOM,
+        RING_RADIUS_VALUE,
+        RING_RADIUS_DENOM,
+        TUBE_RADIUS_VALUE,
+        TUBE_RADIUS_DENOM,
+        C0,
+        C1,
+        C2,
+        C3,
+        C4,
+        C5,
+        C6,
+        C7,
+        C8,
+        C9,
+        C10,
+        C11,
+        C12,
+    >
+{
+    /// **Render** one ASCII frame **in-place**, like
+    /// [`Self::render_frame_in_place`], but split across `threads` worker
+    /// threads for large `WIDTH`×`HEIGHT`/frame-rate workloads.
+    ///
+    /// The `NUM_J` ring-slices are handed out to workers one at a time from
+    /// a shared atomic counter (a work-stealing split) rather than a fixed
+    /// chunk per thread, since slices facing the viewer project onto many
+    /// more pixels than ones seen edge-on and a static split would leave
+    /// faster threads idle. Each worker accumulates into its own private
+    /// `CELLS`-sized `(char, depth)` tile; once every worker finishes, the
+    /// tiles are combined into `output`/`zbuf` with a depth-max reduction.
+    ///
+    /// Every ring's `(j_cos, j_sin)` is looked up from a table built once,
+    /// up front, by walking the same incremental Taylor-renormalized
+    /// recurrence [`Self::render_frame_in_place`] uses, rather than
+    /// recomputed per-ring from a closed-form angle (which very visibly
+    /// diverges from the serial path's accumulated rounding after enough
+    /// rings). Handing out pre-computed rings this way is what actually
+    /// gets identical output to the serial renderer, not just something
+    /// close to it.
+    ///
+    /// `threads` is clamped to at least `1`. Requires the `parallel` Cargo
+    /// feature, which makes the whole crate depend on `std`.
+    pub fn render_frame_parallel_in_place(&self, output: &mut [char], zbuf: &mut [f32], threads: usize) {
+        output.fill(C0);
+        zbuf.fill(0.0);
+
+        let threads = threads.max(1);
+        let ring_angles = Self::ring_angles();
+        let next_j = AtomicUsize::new(0);
+
+        let tiles: Vec<(Vec<char>, Vec<f32>)> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..threads)
+                .map(|_| scope.spawn(|| self.render_tile(&ring_angles, &next_j)))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("donut parallel render worker panicked"))
+                .collect()
+        });
+
+        for (tile_chars, tile_depth) in tiles {
+            for idx in 0..Self::CELLS {
+                if tile_depth[idx] > zbuf[idx] {
+                    zbuf[idx] = tile_depth[idx];
+                    output[idx] = tile_chars[idx];
+                }
+            }
+        }
+    }
+
+    /// Walks the same incremental `(3-|v|^2)/2` Taylor-renormalized
+    /// recurrence [`Self::render_frame_in_place`] steps `j_cos`/`j_sin`
+    /// with, recording every ring's `(j_cos, j_sin)` before stepping to the
+    /// next one. Building this table once up front (instead of letting
+    /// each ring's angle be recomputed independently) is what lets worker
+    /// threads claim rings out of order without drifting from the serial
+    /// renderer's accumulated rounding.
+    fn ring_angles() -> Vec<(f32, f32)> {
+        let mut angles = Vec::with_capacity(Self::NUM_J);
+
+        let mut j_cos = 1.0;
+        let mut j_sin = 0.0;
+
+        for _ in 0..Self::NUM_J {
+            angles.push((j_cos, j_sin));
+
+            let temp = j_cos;
+
+            j_cos -= Self::J_STEP * j_sin;
+            j_sin += Self::J_STEP * temp;
+
+            let norm = (3.0 - (j_cos * j_cos + j_sin * j_sin)) / 2.0;
+
+            j_cos *= norm;
+            j_sin *= norm;
+        }
+
+        angles
+    }
+
+    /// Renders every ring-slice claimed from `next_j` into a private,
+    /// `CELLS`-sized `(char, depth)` tile, leaving unclaimed or
+    /// never-written cells at `C0`/`0.0` so the depth-max merge in
+    /// [`Self::render_frame_parallel_in_place`] leaves the corresponding
+    /// cell in the combined buffers untouched.
+    fn render_tile(&self, ring_angles: &[(f32, f32)], next_j: &AtomicUsize) -> (Vec<char>, Vec<f32>) {
+        let mut chars = vec![C0; Self::CELLS];
+        let mut depth = vec![0.0f32; Self::CELLS];
+
+        let rotation = &self.rotation;
+
+        loop {
+            let j = next_j.fetch_add(1, Ordering::Relaxed);
+            if j >= ring_angles.len() {
+                break;
+            }
+
+            let (j_cos, j_sin) = ring_angles[j];
+
+            let mut i_cos = 1.0;
+            let mut i_sin = 0.0;
+
+            for _ in 0..Self::NUM_I {
+                let h = Self::TUBE_RADIUS * j_cos + Self::RING_RADIUS;
+                let z0 = Self::TUBE_RADIUS * j_sin;
+                let world = apply_rotation(rotation, [i_cos * h, i_sin * h, z0]);
+                let d = 1.0 / (world[2] + VIEWER_DISTANCE as f32);
+
+                let x = (Self::X_CENTER + Self::X_SCALE * d * world[0]) as isize;
+                let y = (Self::Y_CENTER + Self::Y_SCALE * d * world[1]) as isize;
+
+                if x >= 0 && x < WIDTH as isize && y >= 0 && y < HEIGHT as isize {
+                    let idx = (y * (WIDTH as isize) + x) as usize;
+
+                    if d > depth[idx] {
+                        depth[idx] = d;
+
+                        let normal = Self::surface_normal(j_cos, j_sin, i_cos, i_sin, rotation);
+                        let shade = self.lighting.shade(normal, Self::VIEW_DIR);
+                        let n = (BRIGHTNESS_FACTOR as f32 * shade) as isize;
+
+                        chars[idx] = Self::BRIGHTNESS_RAMP[n.clamp(0, 12) as usize];
+                    }
+                }
+                {
+                    let temp = i_cos;
+
+                    i_cos -= Self::I_STEP * i_sin;
+                    i_sin += Self::I_STEP * temp;
+
+                    let norm = (3.0 - (i_cos * i_cos + i_sin * i_sin)) / 2.0;
+
+                    i_cos *= norm;
+                    i_sin *= norm;
+                }
+            }
+        }
+
+        (chars, depth)
+    }
+}
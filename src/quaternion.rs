@@ -0,0 +1,102 @@
+//! A unit quaternion orientation, for rotating the donut about an
+//! arbitrary axis instead of only the two fixed axes [`crate::Donut::rotate`]
+//! advances.
+
+use crate::fastmath::{inv_sqrt, sin_cos_approx};
+
+/// A unit quaternion `w + xi + yj + zk` representing an orientation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub w: f32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Quaternion {
+    /// The identity orientation (no rotation).
+    pub const IDENTITY: Self = Self {
+        w: 1.0,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+    };
+
+    /// Builds the quaternion `(cos(angle/2), sin(angle/2) * axis)` that
+    /// represents a rotation of `angle` radians about `axis` (normalized
+    /// internally, so it need not already be a unit vector).
+    pub fn from_axis_angle(axis: [f32; 3], angle: f32) -> Self {
+        let [ax, ay, az] = axis;
+        let inv_len = inv_sqrt(ax * ax + ay * ay + az * az);
+
+        let (sin_half, cos_half) = sin_cos_approx(angle * 0.5);
+
+        Self {
+            w: cos_half,
+            x: ax * inv_len * sin_half,
+            y: ay * inv_len * sin_half,
+            z: az * inv_len * sin_half,
+        }
+    }
+
+    /// Hamilton product `self * rhs`: applies `rhs`'s rotation first, then
+    /// `self`'s.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, rhs: Self) -> Self {
+        Self {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        }
+    }
+
+    /// The squared norm `w^2 + x^2 + y^2 + z^2`, which should stay close
+    /// to `1.0` for a well-behaved unit quaternion.
+    pub fn norm_squared(self) -> f32 {
+        self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Whether every component is finite (not NaN or infinite).
+    pub fn is_finite(self) -> bool {
+        self.w.is_finite() && self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Rescales `self` to unit norm. Behavior is unspecified if `self` is
+    /// the zero quaternion; callers should use [`Self::is_finite`] and
+    /// [`Self::norm_squared`] to guard against that first.
+    pub fn normalized(self) -> Self {
+        let inv_len = inv_sqrt(self.norm_squared());
+
+        Self {
+            w: self.w * inv_len,
+            x: self.x * inv_len,
+            y: self.y * inv_len,
+            z: self.z * inv_len,
+        }
+    }
+
+    /// Converts this unit quaternion to the 3x3 rotation matrix `M` it
+    /// represents, such that `M * v` rotates a vector `v` the same way the
+    /// quaternion does.
+    ///
+    /// Unlike reading off a handful of matrix entries as Euler
+    /// cosines/sines (which only holds up for the specific X-then-Z
+    /// composition [`crate::Donut::rotate`] produces), the full matrix
+    /// renders any orientation, built from any axis, faithfully.
+    pub fn to_rotation_matrix(self) -> [[f32; 3]; 3] {
+        let Self { w, x, y, z } = self;
+
+        [
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+            [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+            [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+        ]
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
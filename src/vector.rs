@@ -0,0 +1,29 @@
+//! Vector/wireframe output types for driving non-raster displays — a
+//! galvanometer-based laser projector, for instance — that consume an
+//! ordered stream of points with per-point brightness and blanking rather
+//! than a pixel grid.
+
+/// One point in a projected vector frame, in normalized `[-1, 1]` screen
+/// coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+
+    /// Brightness in `[0, 1]`.
+    pub brightness: f32,
+
+    /// Whether the beam should be off while moving to this point (true at
+    /// the start of each new scan-path segment, so separate rings of the
+    /// torus aren't connected by a stray line).
+    pub blanked: bool,
+}
+
+/// The result of a frame-pacing computation: how far to rotate each axis
+/// this frame, and how many points fit in the frame's time budget.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FramePacing {
+    pub da: f32,
+    pub db: f32,
+    pub point_budget: usize,
+}